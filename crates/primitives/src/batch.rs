@@ -0,0 +1,115 @@
+use ethers_core::types::Block;
+
+use crate::{BlockNumber, Header, InvalidBlockError};
+
+/// Puts fetched headers in chain order and drops duplicates before a batch insert.
+///
+/// Returns the headers sorted by number (then hash), with repeated hashes removed, plus every
+/// number missing between the lowest and highest header. Competing headers at the same number
+/// are both kept.
+///
+/// Deduplication is by `hash` rather than `==`, because refetching a block can change fields
+/// such as `total_difficulty` that the hash does not commit to. The first of the sorted copies
+/// is kept.
+pub fn normalize_batch(mut headers: Vec<Header>) -> (Vec<Header>, Vec<BlockNumber>) {
+    headers.sort();
+    headers.dedup_by_key(|header| header.hash);
+
+    let missing = headers
+        .windows(2)
+        .flat_map(|pair| pair[0].number.saturating_add(1)..pair[1].number)
+        .collect();
+
+    (headers, missing)
+}
+
+/// Converts a fetched batch of blocks, keyed by the number each was requested at.
+///
+/// Returns the headers that converted alongside the requested number and error of each block
+/// that did not, so the caller can store the good ones and decide whether to retry the rest.
+pub fn convert_batch<TX>(
+    blocks: impl IntoIterator<Item = (BlockNumber, Block<TX>)>,
+) -> (Vec<Header>, Vec<(BlockNumber, InvalidBlockError)>) {
+    let mut headers = Vec::new();
+    let mut failures = Vec::new();
+    for (number, block) in blocks {
+        match Header::try_from(&block) {
+            Ok(header) => headers.push(header),
+            Err(error) => failures.push((number, error)),
+        }
+    }
+    (headers, failures)
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers_core::types::{H160, H256, U64};
+
+    use super::*;
+
+    fn header(number: BlockNumber, hash: u8) -> Header {
+        Header {
+            number,
+            hash: H256::repeat_byte(hash),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn normalize_batch_sorts_dedups_and_reports_gaps() {
+        let fetched = vec![
+            header(5, 0x05),
+            header(2, 0x02),
+            header(7, 0x07),
+            header(1, 0x01),
+            header(5, 0x05),
+            Header {
+                size: Some(1_024),
+                ..header(2, 0x02)
+            },
+            header(3, 0x03),
+        ];
+
+        let (headers, missing) = normalize_batch(fetched);
+
+        let numbers: Vec<_> = headers.iter().map(|h| h.number).collect();
+        assert_eq!(numbers, [1, 2, 3, 5, 7]);
+        assert_eq!(missing, [4, 6]);
+    }
+
+    #[test]
+    fn normalize_batch_keeps_forks() {
+        let (headers, missing) =
+            normalize_batch(vec![header(2, 0x02), header(1, 0x01), header(2, 0x22)]);
+
+        let order: Vec<_> = headers.iter().map(|h| (h.number, h.hash)).collect();
+        assert_eq!(
+            order,
+            [
+                (1, H256::repeat_byte(0x01)),
+                (2, H256::repeat_byte(0x02)),
+                (2, H256::repeat_byte(0x22)),
+            ]
+        );
+        assert!(missing.is_empty());
+    }
+
+    #[test]
+    fn convert_batch_keeps_good_blocks_and_records_failures() {
+        let blocks = (1..=5u64).map(|number| {
+            let block = Block::<H256> {
+                hash: (number != 3).then(|| H256::from_low_u64_be(number)),
+                number: Some(U64::from(number)),
+                author: Some(H160::zero()),
+                ..Default::default()
+            };
+            (number, block)
+        });
+
+        let (headers, failures) = convert_batch(blocks);
+
+        let numbers: Vec<_> = headers.iter().map(|h| h.number).collect();
+        assert_eq!(numbers, [1, 2, 4, 5]);
+        assert_eq!(failures, [(3, InvalidBlockError::MissingHash)]);
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{BlockHash, BlockNumber};
+
+/// Identifies a stored block the way RPC methods do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlockId {
+    Number(BlockNumber),
+    Hash(BlockHash),
+    /// The highest stored block.
+    Latest,
+    /// The lowest stored block.
+    Earliest,
+}
+
+impl From<BlockNumber> for BlockId {
+    fn from(number: BlockNumber) -> Self {
+        BlockId::Number(number)
+    }
+}
+
+impl From<BlockHash> for BlockId {
+    fn from(hash: BlockHash) -> Self {
+        BlockId::Hash(hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn block_id_from_number_and_hash() {
+        let hash = BlockHash::repeat_byte(0x01);
+
+        assert_eq!(BlockId::from(42), BlockId::Number(42));
+        assert_eq!(BlockId::from(hash), BlockId::Hash(hash));
+    }
+}
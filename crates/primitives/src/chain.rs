@@ -0,0 +1,130 @@
+use std::{
+    hash::{Hash, Hasher},
+    time::Duration,
+};
+
+use crate::BlockNumber;
+
+/// A known EVM network, or any other chain by id.
+///
+/// Chains are identified by [`Chain::chain_id`]: `Chain::Custom(1)` equals `Chain::Mainnet` and
+/// reports the same metadata. Prefer [`Chain::from`] so known ids get their named variant.
+#[derive(Debug, Clone, Copy)]
+pub enum Chain {
+    Mainnet,
+    Sepolia,
+    Holesky,
+    /// Any chain by id, including known ones: `Chain::Custom(1) == Chain::Mainnet` holds, yet
+    /// `matches!(Chain::Custom(1), Chain::Custom(_))` is also true. Call [`Chain::normalized`]
+    /// before matching on the variant.
+    Custom(u64),
+}
+
+impl Chain {
+    pub fn chain_id(&self) -> u64 {
+        match self {
+            Chain::Mainnet => 1,
+            Chain::Sepolia => 11_155_111,
+            Chain::Holesky => 17_000,
+            Chain::Custom(id) => *id,
+        }
+    }
+
+    /// Resolves a `Custom` chain holding a known id to its named variant, so that matching on
+    /// the result agrees with `==`.
+    pub fn normalized(&self) -> Chain {
+        Chain::from(self.chain_id())
+    }
+
+    /// The first block with an EIP-1559 base fee, or `None` if unknown for this chain.
+    pub fn london_block(&self) -> Option<BlockNumber> {
+        match self.normalized() {
+            Chain::Mainnet => Some(12_965_000),
+            Chain::Sepolia | Chain::Holesky => Some(0),
+            Chain::Custom(_) => None,
+        }
+    }
+
+    /// The first proof-of-stake block, or `None` if unknown for this chain.
+    pub fn merge_block(&self) -> Option<BlockNumber> {
+        match self.normalized() {
+            Chain::Mainnet => Some(15_537_394),
+            Chain::Sepolia => Some(1_450_409),
+            Chain::Holesky => Some(0),
+            Chain::Custom(_) => None,
+        }
+    }
+
+    /// The target slot time, or `None` if unknown for this chain.
+    pub fn expected_block_time(&self) -> Option<Duration> {
+        match self.normalized() {
+            Chain::Mainnet | Chain::Sepolia | Chain::Holesky => Some(Duration::from_secs(12)),
+            Chain::Custom(_) => None,
+        }
+    }
+}
+
+impl PartialEq for Chain {
+    fn eq(&self, other: &Self) -> bool {
+        self.chain_id() == other.chain_id()
+    }
+}
+
+impl Eq for Chain {}
+
+impl Hash for Chain {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.chain_id().hash(state);
+    }
+}
+
+impl From<u64> for Chain {
+    fn from(chain_id: u64) -> Self {
+        match chain_id {
+            1 => Chain::Mainnet,
+            11_155_111 => Chain::Sepolia,
+            17_000 => Chain::Holesky,
+            id => Chain::Custom(id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mainnet_constants() {
+        let chain = Chain::Mainnet;
+
+        assert_eq!(chain.chain_id(), 1);
+        assert_eq!(chain.london_block(), Some(12_965_000));
+        assert_eq!(chain.merge_block(), Some(15_537_394));
+        assert_eq!(chain.expected_block_time(), Some(Duration::from_secs(12)));
+    }
+
+    #[test]
+    fn chain_id_round_trip() {
+        for chain in [
+            Chain::Mainnet,
+            Chain::Sepolia,
+            Chain::Holesky,
+            Chain::Custom(42_161),
+        ] {
+            assert_eq!(Chain::from(chain.chain_id()), chain);
+        }
+        assert_eq!(Chain::Custom(42_161).merge_block(), None);
+    }
+
+    #[test]
+    fn custom_with_known_id_is_that_chain() {
+        assert!(matches!(Chain::from(1), Chain::Mainnet));
+        assert_eq!(Chain::from(1), Chain::Mainnet);
+        assert_eq!(Chain::Custom(1), Chain::Mainnet);
+        assert_eq!(Chain::Custom(1).merge_block(), Some(15_537_394));
+        assert_ne!(Chain::Custom(5), Chain::Mainnet);
+
+        assert!(matches!(Chain::Custom(1).normalized(), Chain::Mainnet));
+        assert!(matches!(Chain::Custom(5).normalized(), Chain::Custom(5)));
+    }
+}
@@ -0,0 +1,35 @@
+use ethers_core::types::H256;
+
+/// Uncles hash of a block without uncles: keccak256 of the RLP-encoded empty list.
+pub const EMPTY_UNCLE_HASH: H256 = H256([
+    0x1d, 0xcc, 0x4d, 0xe8, 0xde, 0xc7, 0x5d, 0x7a, 0xab, 0x85, 0xb5, 0x67, 0xb6, 0xcc, 0xd4, 0x1a,
+    0xd3, 0x12, 0x45, 0x1b, 0x94, 0x8a, 0x74, 0x13, 0xf0, 0xa1, 0x42, 0xfd, 0x40, 0xd4, 0x93, 0x47,
+]);
+
+/// Root of an empty Merkle-Patricia trie: keccak256 of the RLP-encoded empty string.
+///
+/// This is the transactions, receipts and withdrawals root of a block with none of them.
+pub const EMPTY_TRIE_ROOT: H256 = H256([
+    0x56, 0xe8, 0x1f, 0x17, 0x1b, 0xcc, 0x55, 0xa6, 0xff, 0x83, 0x45, 0xe6, 0x92, 0xc0, 0xf8, 0x6e,
+    0x5b, 0x48, 0xe0, 0x1b, 0x99, 0x6c, 0xad, 0xc0, 0x01, 0x62, 0x2f, 0xb5, 0xe3, 0x63, 0xb4, 0x21,
+]);
+
+#[cfg(test)]
+mod tests {
+    use ethers_core::utils::{keccak256, rlp::RlpStream};
+
+    use super::*;
+
+    #[test]
+    fn empty_uncle_hash_is_keccak_of_empty_list() {
+        let empty_list = RlpStream::new_list(0).out();
+        assert_eq!(EMPTY_UNCLE_HASH, H256(keccak256(empty_list)));
+    }
+
+    #[test]
+    fn empty_trie_root_is_keccak_of_empty_string() {
+        let mut stream = RlpStream::new();
+        stream.append_empty_data();
+        assert_eq!(EMPTY_TRIE_ROOT, H256(keccak256(stream.out())));
+    }
+}
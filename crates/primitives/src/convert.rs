@@ -0,0 +1,246 @@
+use ethers_core::types::{Block, H256, U256};
+
+use crate::{sum_withdrawals, Header};
+
+/// Why an RPC block could not be converted into a [`Header`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum InvalidBlockError {
+    #[error("block has no hash")]
+    MissingHash,
+    #[error("block has no number")]
+    MissingNumber,
+    #[error("block has no author")]
+    MissingAuthor,
+    #[error("block field {0} does not fit in 64 bits")]
+    Overflow(&'static str),
+    #[error("block field {0} is malformed")]
+    Malformed(&'static str),
+}
+
+/// Reads the EIP-7685 `requestsHash` of a Prague block.
+///
+/// ethers-core has no field for it, so it is taken from the block's unrecognized fields.
+/// Returns `None` for blocks without one.
+pub fn requests_hash<TX>(block: &Block<TX>) -> Result<Option<H256>, InvalidBlockError> {
+    block
+        .other
+        .get_deserialized::<H256>("requestsHash")
+        .transpose()
+        .map_err(|_| InvalidBlockError::Malformed("requestsHash"))
+}
+
+fn to_u64(value: U256, field: &'static str) -> Result<u64, InvalidBlockError> {
+    u64::try_from(value).map_err(|_| InvalidBlockError::Overflow(field))
+}
+
+impl<TX> TryFrom<&Block<TX>> for Header {
+    type Error = InvalidBlockError;
+
+    fn try_from(block: &Block<TX>) -> Result<Self, Self::Error> {
+        let withdrawals_count = match &block.withdrawals {
+            Some(withdrawals) => u32::try_from(withdrawals.len())
+                .map_err(|_| InvalidBlockError::Overflow("withdrawals"))?,
+            None => 0,
+        };
+
+        Ok(Header {
+            hash: block.hash.ok_or(InvalidBlockError::MissingHash)?,
+            parent_hash: block.parent_hash,
+            uncles_hash: block.uncles_hash,
+            author: block.author.ok_or(InvalidBlockError::MissingAuthor)?,
+            state_root: block.state_root,
+            transactions_root: block.transactions_root,
+            receipts_root: block.receipts_root,
+            number: block
+                .number
+                .ok_or(InvalidBlockError::MissingNumber)?
+                .as_u64(),
+            gas_used: to_u64(block.gas_used, "gas_used")?,
+            gas_limit: to_u64(block.gas_limit, "gas_limit")?,
+            extra_data: block.extra_data.clone(),
+            timestamp: to_u64(block.timestamp, "timestamp")?,
+            difficulty: block.difficulty,
+            total_difficulty: block.total_difficulty,
+            mix_hash: block.mix_hash,
+            nonce: block.nonce,
+            base_fee_per_gas: block
+                .base_fee_per_gas
+                .map(|base_fee| to_u64(base_fee, "base_fee_per_gas"))
+                .transpose()?,
+            size: block.size.map(|size| to_u64(size, "size")).transpose()?,
+            uncles: block.uncles.clone(),
+            withdrawals_count,
+            total_withdrawn: block.withdrawals.as_deref().map(sum_withdrawals),
+            requests_hash: requests_hash(block)?,
+            block_time: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers_core::types::{Withdrawal, H160, H256, U64};
+
+    use super::*;
+
+    fn block() -> Block<H256> {
+        Block {
+            hash: Some(H256::repeat_byte(0x02)),
+            parent_hash: H256::repeat_byte(0x01),
+            author: Some(H160::repeat_byte(0x0a)),
+            number: Some(U64::from(17_585_076)),
+            gas_used: U256::from(10_241_365),
+            gas_limit: U256::from(30_000_000),
+            timestamp: U256::from(1_688_043_143),
+            base_fee_per_gas: Some(U256::from(26_097_588_307u64)),
+            size: Some(U256::from(60_000)),
+            withdrawals: Some(vec![
+                Withdrawal {
+                    amount: U256::from(14_073_367),
+                    ..Default::default()
+                },
+                Withdrawal {
+                    amount: U256::from(49_304_201),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn converts_block() {
+        let block = block();
+
+        let header = Header::try_from(&block).unwrap();
+
+        assert_eq!(header.hash, H256::repeat_byte(0x02));
+        assert_eq!(header.parent_hash, block.parent_hash);
+        assert_eq!(header.author, H160::repeat_byte(0x0a));
+        assert_eq!(header.number, 17_585_076);
+        assert_eq!(header.gas_used, 10_241_365);
+        assert_eq!(header.gas_limit, 30_000_000);
+        assert_eq!(header.timestamp, 1_688_043_143);
+        assert_eq!(header.base_fee_per_gas, Some(26_097_588_307));
+        assert_eq!(header.size, Some(60_000));
+        assert_eq!(header.withdrawals_count, 2);
+        assert_eq!(header.total_withdrawn, Some(U256::from(63_377_568)));
+    }
+
+    #[test]
+    fn pre_shanghai_block_has_no_withdrawals() {
+        let block = Block {
+            withdrawals: None,
+            ..block()
+        };
+
+        let header = Header::try_from(&block).unwrap();
+
+        assert_eq!(header.withdrawals_count, 0);
+        assert_eq!(header.total_withdrawn, None);
+    }
+
+    #[test]
+    fn rejects_invalid_blocks() {
+        let missing_hash = Block {
+            hash: None,
+            ..block()
+        };
+        let missing_number = Block {
+            number: None,
+            ..block()
+        };
+        let missing_author = Block {
+            author: None,
+            ..block()
+        };
+        let oversized_gas = Block {
+            gas_used: U256::from(u64::MAX) + 1,
+            ..block()
+        };
+
+        assert_eq!(
+            Header::try_from(&missing_hash),
+            Err(InvalidBlockError::MissingHash)
+        );
+        assert_eq!(
+            Header::try_from(&missing_number),
+            Err(InvalidBlockError::MissingNumber)
+        );
+        assert_eq!(
+            Header::try_from(&missing_author),
+            Err(InvalidBlockError::MissingAuthor)
+        );
+        assert_eq!(
+            Header::try_from(&oversized_gas),
+            Err(InvalidBlockError::Overflow("gas_used"))
+        );
+    }
+
+    /// A Prague devnet block carrying an EIP-7685 `requestsHash`.
+    const PRAGUE_BLOCK: &str = r#"{
+        "hash": "0x661da523f3e44725f3a1cee38183d35424155a05674609a9f6ed81243adf9e26",
+        "parentHash": "0x60f1563d2c572116091a4b91421d8d972118e39604d23455d841f9431cea4b6a",
+        "sha3Uncles": "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347",
+        "miner": "0xf97e180c050e5ab072211ad2c213eb5aee4df134",
+        "stateRoot": "0x8101d88f2761eb9849634740f92fe09735551ad5a4d5e9da9bcae1ef4726a475",
+        "transactionsRoot": "0xf543eb3d405d2d6320344d348b06703ff1abeef71288181a24061e53f89bb5ef",
+        "receiptsRoot": "0xeaa8c40899a61ae59615cf9985f5e2194f8fd2b57d273be63bde6733e89b12ab",
+        "logsBloom": null,
+        "difficulty": "0x0",
+        "number": "0x315",
+        "gasLimit": "0x1c9c380",
+        "gasUsed": "0x5208",
+        "timestamp": "0x6712ba6e",
+        "extraData": "0xd883010e0c846765746888676f312e32332e32856c696e7578",
+        "mixHash": "0xe6d9c084dd36560520d5776a5387a82fb44793c9cd1b69afb61d53af29ee64b0",
+        "nonce": "0x0000000000000000",
+        "baseFeePerGas": "0x7",
+        "withdrawalsRoot": "0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421",
+        "blobGasUsed": "0x20000",
+        "excessBlobGas": "0x40000",
+        "parentBeaconBlockRoot": "0xd0bdb48ab45028568e66c8ddd600ac4c2a52522714bbfbf00ea6d20ba40f3ae2",
+        "requestsHash": "0x6036c41849da9c076ed79654d434017387a88fb833c2856b32e18218b3341c5f",
+        "uncles": [],
+        "transactions": []
+    }"#;
+
+    #[test]
+    fn reads_requests_hash_when_present() {
+        let block: Block<H256> = serde_json::from_str(PRAGUE_BLOCK).unwrap();
+        let expected: H256 = "0x6036c41849da9c076ed79654d434017387a88fb833c2856b32e18218b3341c5f"
+            .parse()
+            .unwrap();
+
+        assert_eq!(requests_hash(&block), Ok(Some(expected)));
+        assert_eq!(
+            Header::try_from(&block).unwrap().requests_hash,
+            Some(expected)
+        );
+    }
+
+    #[test]
+    fn requests_hash_is_none_before_prague() {
+        let block = block();
+
+        assert_eq!(requests_hash(&block), Ok(None));
+        assert_eq!(Header::try_from(&block).unwrap().requests_hash, None);
+    }
+
+    #[test]
+    fn rejects_malformed_requests_hash() {
+        let mut block = block();
+        block
+            .other
+            .insert("requestsHash".to_owned(), serde_json::json!("0x1234"));
+
+        assert_eq!(
+            requests_hash(&block),
+            Err(InvalidBlockError::Malformed("requestsHash"))
+        );
+        assert_eq!(
+            Header::try_from(&block),
+            Err(InvalidBlockError::Malformed("requestsHash"))
+        );
+    }
+}
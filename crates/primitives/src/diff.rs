@@ -0,0 +1,123 @@
+use std::fmt;
+
+use crate::Header;
+
+/// A single field that differs between two headers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDiff {
+    pub field: &'static str,
+    pub old: String,
+    pub new: String,
+}
+
+impl fmt::Display for FieldDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} -> {}", self.field, self.old, self.new)
+    }
+}
+
+/// Compares each field bound from the old header against the same field on `$new`.
+macro_rules! diff_fields {
+    ($new:expr, $($field:ident),+ $(,)?) => {{
+        let mut diffs = Vec::new();
+        $(
+            if *$field != $new.$field {
+                diffs.push(FieldDiff {
+                    field: stringify!($field),
+                    old: format!("{:?}", $field),
+                    new: format!("{:?}", $new.$field),
+                });
+            }
+        )+
+        diffs
+    }};
+}
+
+/// Returns every field that differs between two headers, in declaration order.
+///
+/// Meant for logging what a fork changed when two headers compete for the same number.
+pub fn diff(old: &Header, new: &Header) -> Vec<FieldDiff> {
+    // Destructured without `..` so that adding a field to `Header` fails to compile here
+    // until it is diffed too.
+    let Header {
+        hash,
+        parent_hash,
+        uncles_hash,
+        author,
+        state_root,
+        transactions_root,
+        receipts_root,
+        number,
+        gas_used,
+        gas_limit,
+        extra_data,
+        timestamp,
+        difficulty,
+        total_difficulty,
+        mix_hash,
+        nonce,
+        base_fee_per_gas,
+        size,
+        uncles,
+        withdrawals_count,
+        total_withdrawn,
+        requests_hash,
+        block_time,
+    } = old;
+
+    diff_fields!(
+        new,
+        hash,
+        parent_hash,
+        uncles_hash,
+        author,
+        state_root,
+        transactions_root,
+        receipts_root,
+        number,
+        gas_used,
+        gas_limit,
+        extra_data,
+        timestamp,
+        difficulty,
+        total_difficulty,
+        mix_hash,
+        nonce,
+        base_fee_per_gas,
+        size,
+        uncles,
+        withdrawals_count,
+        total_withdrawn,
+        requests_hash,
+        block_time,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers_core::types::{H160, H256};
+
+    use super::*;
+
+    #[test]
+    fn diff_reports_changed_fields() {
+        let old = Header {
+            number: 100,
+            author: H160::repeat_byte(0x01),
+            state_root: H256::repeat_byte(0x0a),
+            ..Default::default()
+        };
+        let new = Header {
+            author: H160::repeat_byte(0x02),
+            state_root: H256::repeat_byte(0x0b),
+            ..old.clone()
+        };
+
+        let diffs = diff(&old, &new);
+        let fields: Vec<_> = diffs.iter().map(|d| d.field).collect();
+        assert_eq!(fields, ["author", "state_root"]);
+        assert_eq!(diffs[0].old, format!("{:?}", old.author));
+        assert_eq!(diffs[0].new, format!("{:?}", new.author));
+        assert!(diff(&old, &old).is_empty());
+    }
+}
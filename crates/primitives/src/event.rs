@@ -0,0 +1,206 @@
+use std::io::Read;
+
+use ethers_core::{
+    abi::{self, Contract, Event, RawLog, Token},
+    types::{Log, H160, H256},
+};
+
+use crate::BlockNumber;
+
+/// Why an event filter could not be built or a log could not be decoded.
+#[derive(Debug, thiserror::Error)]
+pub enum EventFilterError {
+    #[error("ABI has no event named {0}")]
+    UnknownEvent(String),
+    #[error(transparent)]
+    Abi(#[from] abi::Error),
+}
+
+/// A log decoded against the ABI event whose topic0 it carries.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedLog {
+    pub event: String,
+    pub address: H160,
+    pub block_number: Option<BlockNumber>,
+    pub transaction_hash: Option<H256>,
+    pub log_index: Option<u64>,
+    /// Indexed and non-indexed parameters, in ABI order.
+    pub params: Vec<(String, Token)>,
+}
+
+impl DecodedLog {
+    /// Returns the decoded value of the parameter called `name`.
+    pub fn param(&self, name: &str) -> Option<&Token> {
+        self.params
+            .iter()
+            .find(|(param, _)| param == name)
+            .map(|(_, value)| value)
+    }
+}
+
+/// Selects and decodes the logs of a chosen set of events from a contract ABI.
+#[derive(Debug, Clone)]
+pub struct EventFilter {
+    events: Vec<Event>,
+}
+
+impl EventFilter {
+    /// Reads a JSON ABI and keeps the events called `names`.
+    ///
+    /// Anonymous events have no topic0 to match on, so naming one is an error.
+    pub fn from_abi_json(abi: impl Read, names: &[&str]) -> Result<Self, EventFilterError> {
+        let contract = Contract::load(abi)?;
+        let events = names
+            .iter()
+            .map(|&name| match contract.event(name) {
+                Ok(event) if !event.anonymous => Ok(event.clone()),
+                _ => Err(EventFilterError::UnknownEvent(name.to_owned())),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { events })
+    }
+
+    /// The topic0 hashes of the selected events, for an `eth_getLogs` filter.
+    pub fn topics(&self) -> Vec<H256> {
+        self.events.iter().map(Event::signature).collect()
+    }
+
+    /// Returns true if `log` was emitted by one of the selected events.
+    pub fn matches(&self, log: &Log) -> bool {
+        self.event_for(log).is_some()
+    }
+
+    /// Decodes `log`, or returns `Ok(None)` if it is not one of the selected events.
+    pub fn decode(&self, log: &Log) -> Result<Option<DecodedLog>, EventFilterError> {
+        let Some(event) = self.event_for(log) else {
+            return Ok(None);
+        };
+        let parsed = event.parse_log(RawLog {
+            topics: log.topics.clone(),
+            data: log.data.to_vec(),
+        })?;
+
+        Ok(Some(DecodedLog {
+            event: event.name.clone(),
+            address: log.address,
+            block_number: log.block_number.map(|number| number.as_u64()),
+            transaction_hash: log.transaction_hash,
+            log_index: log.log_index.map(|index| index.low_u64()),
+            params: parsed
+                .params
+                .into_iter()
+                .map(|param| (param.name, param.value))
+                .collect(),
+        }))
+    }
+
+    fn event_for(&self, log: &Log) -> Option<&Event> {
+        let topic0 = log.topics.first()?;
+        self.events
+            .iter()
+            .find(|event| event.signature() == *topic0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers_core::types::{U256, U64};
+
+    use super::*;
+
+    const ERC20_ABI: &str = r#"[
+        {
+            "type": "event",
+            "name": "Transfer",
+            "anonymous": false,
+            "inputs": [
+                { "name": "from", "type": "address", "indexed": true },
+                { "name": "to", "type": "address", "indexed": true },
+                { "name": "value", "type": "uint256", "indexed": false }
+            ]
+        },
+        {
+            "type": "event",
+            "name": "Approval",
+            "anonymous": false,
+            "inputs": [
+                { "name": "owner", "type": "address", "indexed": true },
+                { "name": "spender", "type": "address", "indexed": true },
+                { "name": "value", "type": "uint256", "indexed": false }
+            ]
+        }
+    ]"#;
+
+    const TRANSFER_TOPIC: &str =
+        "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+
+    fn transfer_log(from: H160, to: H160, value: U256) -> Log {
+        Log {
+            address: H160::repeat_byte(0xc0),
+            topics: vec![
+                TRANSFER_TOPIC.parse().unwrap(),
+                H256::from(from),
+                H256::from(to),
+            ],
+            data: abi::encode(&[Token::Uint(value)]).into(),
+            block_number: Some(U64::from(17_585_076)),
+            log_index: Some(U256::from(3)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn derives_topic0_from_abi() {
+        let filter = EventFilter::from_abi_json(ERC20_ABI.as_bytes(), &["Transfer"]).unwrap();
+
+        assert_eq!(filter.topics(), [TRANSFER_TOPIC.parse::<H256>().unwrap()]);
+    }
+
+    #[test]
+    fn decodes_erc20_transfer() {
+        let filter = EventFilter::from_abi_json(ERC20_ABI.as_bytes(), &["Transfer"]).unwrap();
+        let from = H160::repeat_byte(0xaa);
+        let to = H160::repeat_byte(0xbb);
+        let log = transfer_log(from, to, U256::from(1_000_000));
+
+        let decoded = filter.decode(&log).unwrap().unwrap();
+
+        assert_eq!(decoded.event, "Transfer");
+        assert_eq!(decoded.address, log.address);
+        assert_eq!(decoded.block_number, Some(17_585_076));
+        assert_eq!(decoded.log_index, Some(3));
+        assert_eq!(decoded.param("from"), Some(&Token::Address(from)));
+        assert_eq!(decoded.param("to"), Some(&Token::Address(to)));
+        assert_eq!(
+            decoded.param("value"),
+            Some(&Token::Uint(U256::from(1_000_000)))
+        );
+    }
+
+    #[test]
+    fn skips_unselected_events() {
+        let filter = EventFilter::from_abi_json(ERC20_ABI.as_bytes(), &["Approval"]).unwrap();
+        let log = transfer_log(H160::zero(), H160::zero(), U256::one());
+
+        assert!(!filter.matches(&log));
+        assert_eq!(filter.decode(&log).unwrap(), None);
+        assert!(!filter.matches(&Log::default()));
+    }
+
+    #[test]
+    fn rejects_truncated_log_data() {
+        let filter = EventFilter::from_abi_json(ERC20_ABI.as_bytes(), &["Transfer"]).unwrap();
+        let mut log = transfer_log(H160::zero(), H160::zero(), U256::one());
+        log.data = log.data[..16].to_vec().into();
+
+        assert!(filter.matches(&log));
+        assert!(matches!(filter.decode(&log), Err(EventFilterError::Abi(_))));
+    }
+
+    #[test]
+    fn rejects_unknown_event_names() {
+        let error = EventFilter::from_abi_json(ERC20_ABI.as_bytes(), &["Mint"]).unwrap_err();
+
+        assert!(matches!(error, EventFilterError::UnknownEvent(name) if name == "Mint"));
+    }
+}
@@ -0,0 +1,109 @@
+use std::cmp::Ordering;
+
+use ethers_core::types::U256;
+
+use crate::Header;
+
+/// EIP-1559 bound on how much the base fee can change from one block to the next.
+const BASE_FEE_MAX_CHANGE_DENOMINATOR: u128 = 8;
+
+/// EIP-1559 ratio between the gas limit and the gas target.
+const ELASTICITY_MULTIPLIER: u64 = 2;
+
+/// EIP-1559 base fee of the London fork block, 1 gwei.
+pub(crate) const INITIAL_BASE_FEE: u64 = 1_000_000_000;
+
+impl Header {
+    /// Returns the base fee per gas, or `None` for pre-London blocks.
+    pub fn base_fee(&self) -> Option<U256> {
+        self.base_fee_per_gas.map(U256::from)
+    }
+}
+
+/// Computes the base fee of the block following `parent` using the EIP-1559 formula with
+/// Ethereum L1 parameters.
+///
+/// Returns `None` when the parent has no base fee or a gas limit too small to define a target.
+pub fn next_base_fee(parent: &Header) -> Option<u64> {
+    let base_fee = u128::from(parent.base_fee_per_gas?);
+    let gas_target = parent.gas_limit / ELASTICITY_MULTIPLIER;
+    if gas_target == 0 {
+        return None;
+    }
+    let gas_used = parent.gas_used;
+
+    let next = match gas_used.cmp(&gas_target) {
+        Ordering::Equal => base_fee,
+        Ordering::Greater => {
+            let delta = base_fee * u128::from(gas_used - gas_target)
+                / u128::from(gas_target)
+                / BASE_FEE_MAX_CHANGE_DENOMINATOR;
+            base_fee + delta.max(1)
+        }
+        Ordering::Less => {
+            let delta = base_fee * u128::from(gas_target - gas_used)
+                / u128::from(gas_target)
+                / BASE_FEE_MAX_CHANGE_DENOMINATOR;
+            base_fee - delta
+        }
+    };
+
+    u64::try_from(next).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_base_fee_matches_mainnet() {
+        // The London fork block 12965000, whose child 12965001 has a base fee of 1124967822.
+        let parent = Header {
+            number: 12_965_000,
+            gas_used: 30_025_257,
+            gas_limit: 30_029_122,
+            base_fee_per_gas: Some(1_000_000_000),
+            ..Default::default()
+        };
+
+        assert_eq!(parent.base_fee(), Some(U256::from(1_000_000_000u64)));
+        assert_eq!(next_base_fee(&parent), Some(1_124_967_822));
+    }
+
+    #[test]
+    fn next_base_fee_moves_toward_target() {
+        let parent = Header {
+            gas_limit: 30_000_000,
+            base_fee_per_gas: Some(800),
+            ..Default::default()
+        };
+
+        let empty = Header {
+            gas_used: 0,
+            ..parent.clone()
+        };
+        let at_target = Header {
+            gas_used: 15_000_000,
+            ..parent.clone()
+        };
+        let barely_above = Header {
+            gas_used: 15_000_001,
+            ..parent.clone()
+        };
+
+        assert_eq!(next_base_fee(&empty), Some(700));
+        assert_eq!(next_base_fee(&at_target), Some(800));
+        assert_eq!(next_base_fee(&barely_above), Some(801));
+    }
+
+    #[test]
+    fn next_base_fee_requires_london_parent() {
+        let parent = Header {
+            gas_limit: 30_000_000,
+            ..Default::default()
+        };
+
+        assert_eq!(parent.base_fee(), None);
+        assert_eq!(next_base_fee(&parent), None);
+    }
+}
@@ -0,0 +1,373 @@
+use std::cmp::Ordering;
+
+use ethers_core::{
+    types::{Bytes, H160, H256, H64, U256},
+    utils::hex,
+};
+
+use crate::{EMPTY_TRIE_ROOT, EMPTY_UNCLE_HASH};
+
+/// Block number as stored by the indexer.
+pub type BlockNumber = u64;
+
+/// Block hash as stored by the indexer.
+pub type BlockHash = H256;
+
+/// The header portion of an EVM block.
+///
+/// Equality is structural over every field. Two fetches of the same block can still differ in
+/// fields the hash does not commit to, such as `total_difficulty` and `size`, so deduplicate by
+/// `hash` explicitly rather than relying on `==`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Header {
+    pub hash: BlockHash,
+    pub parent_hash: BlockHash,
+    pub uncles_hash: H256,
+    pub author: H160,
+    pub state_root: H256,
+    pub transactions_root: H256,
+    pub receipts_root: H256,
+    pub number: BlockNumber,
+    pub gas_used: u64,
+    pub gas_limit: u64,
+    pub extra_data: Bytes,
+    pub timestamp: u64,
+    pub difficulty: U256,
+    pub total_difficulty: Option<U256>,
+    /// The PoW mix digest. On proof-of-stake blocks this carries the beacon chain's
+    /// prev-RANDAO value instead, see [`Header::randao`].
+    pub mix_hash: Option<H256>,
+    pub nonce: Option<H64>,
+    /// Only present from London onwards.
+    pub base_fee_per_gas: Option<u64>,
+    pub size: Option<u64>,
+    pub uncles: Vec<H256>,
+    /// Number of beacon chain withdrawals, zero before Shanghai.
+    pub withdrawals_count: u32,
+    /// Sum of withdrawal amounts in gwei, `None` before Shanghai.
+    pub total_withdrawn: Option<U256>,
+    /// EIP-7685 commitment to the block's execution layer requests, `None` before Prague.
+    pub requests_hash: Option<H256>,
+    /// Seconds since the parent block, filled in by [`crate::block_time`] once the parent is
+    /// known. `None` for a header converted on its own.
+    pub block_time: Option<u64>,
+}
+
+impl Header {
+    /// Returns true for proof-of-stake blocks, which have zero difficulty since the merge.
+    pub fn is_pos(&self) -> bool {
+        self.difficulty.is_zero()
+    }
+
+    /// Returns the prev-RANDAO value of a proof-of-stake block.
+    ///
+    /// This is the beacon chain RANDAO mix that post-merge blocks carry in `mix_hash`. It is
+    /// `None` for proof-of-work blocks, where `mix_hash` is a PoW digest and not randomness.
+    pub fn randao(&self) -> Option<H256> {
+        if self.is_pos() {
+            self.mix_hash
+        } else {
+            None
+        }
+    }
+
+    /// Returns true when the block includes no uncles.
+    pub fn is_empty_uncles(&self) -> bool {
+        self.uncles_hash == EMPTY_UNCLE_HASH
+    }
+
+    /// Returns true when the block includes no transactions.
+    pub fn is_empty_transactions(&self) -> bool {
+        self.transactions_root == EMPTY_TRIE_ROOT
+    }
+
+    /// The fraction of the gas limit the block used, or `None` if the gas limit is zero.
+    ///
+    /// Matches the `gas_used::float8 / NULLIF(gas_limit, 0)` utilization computed in SQL.
+    pub fn gas_used_ratio(&self) -> Option<f64> {
+        if self.gas_limit == 0 {
+            return None;
+        }
+        Some(self.gas_used as f64 / self.gas_limit as f64)
+    }
+
+    /// Decodes `extra_data` as UTF-8, which miners often use for client or pool names.
+    ///
+    /// Returns `None` if the bytes are not valid UTF-8.
+    pub fn extra_data_text(&self) -> Option<String> {
+        std::str::from_utf8(&self.extra_data)
+            .ok()
+            .map(str::to_owned)
+    }
+
+    /// The block hash as a `0x`-prefixed lowercase hex string.
+    pub fn hash_hex(&self) -> String {
+        format!("{:#x}", self.hash)
+    }
+
+    /// The block hash abbreviated to its first and last four bytes, e.g. `0x12345678…9abcdef0`.
+    pub fn short_hash(&self) -> String {
+        short_hex(&self.hash)
+    }
+
+    /// The parent hash as a `0x`-prefixed lowercase hex string.
+    pub fn parent_hash_hex(&self) -> String {
+        format!("{:#x}", self.parent_hash)
+    }
+
+    /// The parent hash abbreviated like [`Header::short_hash`].
+    pub fn short_parent_hash(&self) -> String {
+        short_hex(&self.parent_hash)
+    }
+}
+
+fn short_hex(hash: &H256) -> String {
+    let bytes = hash.as_bytes();
+    format!(
+        "0x{}…{}",
+        hex::encode(&bytes[..4]),
+        hex::encode(&bytes[bytes.len() - 4..])
+    )
+}
+
+/// Headers sort in chain order by `number`. Competing headers at the same number (forks)
+/// are ordered by `hash` so that sorting is deterministic. The remaining fields break any
+/// further tie in declaration order, which keeps `Ord` consistent with the structural `Eq`.
+impl Ord for Header {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Destructured without `..` so that adding a field to `Header` fails to compile here
+        // until it takes part in the ordering too.
+        let Header {
+            hash,
+            parent_hash,
+            uncles_hash,
+            author,
+            state_root,
+            transactions_root,
+            receipts_root,
+            number,
+            gas_used,
+            gas_limit,
+            extra_data,
+            timestamp,
+            difficulty,
+            total_difficulty,
+            mix_hash,
+            nonce,
+            base_fee_per_gas,
+            size,
+            uncles,
+            withdrawals_count,
+            total_withdrawn,
+            requests_hash,
+            block_time,
+        } = self;
+
+        number
+            .cmp(&other.number)
+            .then_with(|| hash.cmp(&other.hash))
+            .then_with(|| parent_hash.cmp(&other.parent_hash))
+            .then_with(|| uncles_hash.cmp(&other.uncles_hash))
+            .then_with(|| author.cmp(&other.author))
+            .then_with(|| state_root.cmp(&other.state_root))
+            .then_with(|| transactions_root.cmp(&other.transactions_root))
+            .then_with(|| receipts_root.cmp(&other.receipts_root))
+            .then_with(|| gas_used.cmp(&other.gas_used))
+            .then_with(|| gas_limit.cmp(&other.gas_limit))
+            .then_with(|| extra_data.cmp(&other.extra_data))
+            .then_with(|| timestamp.cmp(&other.timestamp))
+            .then_with(|| difficulty.cmp(&other.difficulty))
+            .then_with(|| total_difficulty.cmp(&other.total_difficulty))
+            .then_with(|| mix_hash.cmp(&other.mix_hash))
+            .then_with(|| nonce.cmp(&other.nonce))
+            .then_with(|| base_fee_per_gas.cmp(&other.base_fee_per_gas))
+            .then_with(|| size.cmp(&other.size))
+            .then_with(|| uncles.cmp(&other.uncles))
+            .then_with(|| withdrawals_count.cmp(&other.withdrawals_count))
+            .then_with(|| total_withdrawn.cmp(&other.total_withdrawn))
+            .then_with(|| requests_hash.cmp(&other.requests_hash))
+            .then_with(|| block_time.cmp(&other.block_time))
+    }
+}
+
+impl PartialOrd for Header {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_pos_follows_difficulty() {
+        let pow = Header {
+            number: 15_537_393,
+            difficulty: U256::from(11_055_787_484_078_698u64),
+            ..Default::default()
+        };
+        let pos = Header {
+            number: 15_537_394,
+            difficulty: U256::zero(),
+            ..Default::default()
+        };
+
+        assert!(!pow.is_pos());
+        assert!(pos.is_pos());
+    }
+
+    #[test]
+    fn randao_only_for_pos() {
+        let mix_hash = Some(H256::repeat_byte(0x5e));
+        let pow = Header {
+            difficulty: U256::from(11_055_787_484_078_698u64),
+            mix_hash,
+            ..Default::default()
+        };
+        let pos = Header {
+            difficulty: U256::zero(),
+            mix_hash,
+            ..Default::default()
+        };
+
+        assert_eq!(pow.randao(), None);
+        assert_eq!(pos.randao(), mix_hash);
+    }
+
+    #[test]
+    fn headers_sort_by_number_then_hash() {
+        let header = |number, hash| Header {
+            number,
+            hash: H256::repeat_byte(hash),
+            ..Default::default()
+        };
+        let mut headers = [
+            header(3, 0x01),
+            header(1, 0x01),
+            header(2, 0x02),
+            header(4, 0x01),
+            header(2, 0x01),
+        ];
+
+        headers.sort();
+
+        let order: Vec<_> = headers.iter().map(|h| (h.number, h.hash)).collect();
+        assert_eq!(
+            order,
+            [
+                (1, H256::repeat_byte(0x01)),
+                (2, H256::repeat_byte(0x01)),
+                (2, H256::repeat_byte(0x02)),
+                (3, H256::repeat_byte(0x01)),
+                (4, H256::repeat_byte(0x01)),
+            ]
+        );
+    }
+
+    #[test]
+    fn ordering_is_consistent_with_equality() {
+        let header = Header {
+            number: 100,
+            hash: H256::repeat_byte(0x01),
+            total_difficulty: Some(U256::from(1)),
+            size: Some(500),
+            ..Default::default()
+        };
+        let refetched = Header {
+            total_difficulty: Some(U256::from(2)),
+            size: Some(501),
+            ..header.clone()
+        };
+        let fork = Header {
+            number: 100,
+            hash: H256::repeat_byte(0x00),
+            size: Some(900),
+            ..Default::default()
+        };
+
+        assert_eq!(header.cmp(&header.clone()), Ordering::Equal);
+        assert_ne!(header, refetched);
+        assert_ne!(header.cmp(&refetched), Ordering::Equal);
+        // Number and hash still take precedence over the remaining fields.
+        assert_eq!(fork.cmp(&header), Ordering::Less);
+        assert_eq!(header.cmp(&refetched), Ordering::Less);
+    }
+
+    #[test]
+    fn empty_roots_are_detected() {
+        let empty = Header {
+            uncles_hash: EMPTY_UNCLE_HASH,
+            transactions_root: EMPTY_TRIE_ROOT,
+            ..Default::default()
+        };
+        let full = Header {
+            uncles_hash: H256::repeat_byte(0x01),
+            transactions_root: H256::repeat_byte(0x02),
+            ..Default::default()
+        };
+
+        assert!(empty.is_empty_uncles());
+        assert!(empty.is_empty_transactions());
+        assert!(!full.is_empty_uncles());
+        assert!(!full.is_empty_transactions());
+    }
+
+    #[test]
+    fn gas_used_ratio_of_gas_limit() {
+        let header = Header {
+            gas_used: 15_000_000,
+            gas_limit: 30_000_000,
+            ..Default::default()
+        };
+        assert_eq!(header.gas_used_ratio(), Some(0.5));
+
+        let full = Header {
+            gas_used: 30_000_000,
+            ..header.clone()
+        };
+        assert_eq!(full.gas_used_ratio(), Some(1.0));
+
+        assert_eq!(Header::default().gas_used_ratio(), None);
+    }
+
+    #[test]
+    fn hash_formatting() {
+        let mut hash = [0xab; 32];
+        hash[..4].copy_from_slice(&[0x12, 0x34, 0x56, 0x78]);
+        hash[28..].copy_from_slice(&[0x9a, 0xbc, 0xde, 0xf0]);
+        let header = Header {
+            hash: H256(hash),
+            parent_hash: H256::repeat_byte(0x0f),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            header.hash_hex(),
+            format!("0x12345678{}9abcdef0", "ab".repeat(24))
+        );
+        assert_eq!(header.short_hash(), "0x12345678…9abcdef0");
+        assert_eq!(header.parent_hash_hex(), format!("0x{}", "0f".repeat(32)));
+        assert_eq!(header.short_parent_hash(), "0x0f0f0f0f…0f0f0f0f");
+    }
+
+    #[test]
+    fn extra_data_text_decodes_utf8_only() {
+        let text = Header {
+            extra_data: Bytes::from_static(b"Geth/v1.0.0/linux/go1.4.2"),
+            ..Default::default()
+        };
+        let binary = Header {
+            extra_data: hex::decode("d883010a17846765746888676f312e32302e35856c696e7578")
+                .unwrap()
+                .into(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            text.extra_data_text().as_deref(),
+            Some("Geth/v1.0.0/linux/go1.4.2")
+        );
+        assert_eq!(binary.extra_data_text(), None);
+    }
+}
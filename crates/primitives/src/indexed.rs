@@ -0,0 +1,117 @@
+use std::collections::BTreeSet;
+
+use crate::BlockNumber;
+
+/// In-memory set of block numbers that have been indexed, for answering gap checks without a
+/// database query.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IndexedSet {
+    numbers: BTreeSet<BlockNumber>,
+}
+
+impl IndexedSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records `number` as indexed. Returns false if it already was.
+    pub fn insert(&mut self, number: BlockNumber) -> bool {
+        self.numbers.insert(number)
+    }
+
+    /// Forgets `number`, e.g. after a reorg deleted it. Returns false if it was not indexed.
+    pub fn remove(&mut self, number: BlockNumber) -> bool {
+        self.numbers.remove(&number)
+    }
+
+    pub fn contains(&self, number: BlockNumber) -> bool {
+        self.numbers.contains(&number)
+    }
+
+    pub fn len(&self) -> usize {
+        self.numbers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.numbers.is_empty()
+    }
+
+    /// Returns every number in `from..=to` that is not indexed, in ascending order.
+    pub fn gaps(&self, from: BlockNumber, to: BlockNumber) -> Vec<BlockNumber> {
+        if from > to {
+            return Vec::new();
+        }
+        let mut gaps = Vec::new();
+        let mut next = Some(from);
+        for &number in self.numbers.range(from..=to) {
+            if let Some(expected) = next {
+                gaps.extend(expected..number);
+            }
+            next = number.checked_add(1);
+        }
+        if let Some(expected) = next {
+            gaps.extend(expected..=to);
+        }
+        gaps
+    }
+}
+
+impl FromIterator<BlockNumber> for IndexedSet {
+    fn from_iter<I: IntoIterator<Item = BlockNumber>>(iter: I) -> Self {
+        Self {
+            numbers: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers_core::types::H256;
+
+    use super::*;
+    use crate::{normalize_batch, Header};
+
+    #[test]
+    fn insert_remove_contains() {
+        let mut set = IndexedSet::new();
+        assert!(set.insert(10));
+        assert!(!set.insert(10));
+        assert!(set.contains(10));
+        assert_eq!(set.len(), 1);
+
+        assert!(set.remove(10));
+        assert!(!set.remove(10));
+        assert!(!set.contains(10));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn gaps_cover_the_whole_range() {
+        let set: IndexedSet = [3, 4, 7].into_iter().collect();
+        assert_eq!(set.gaps(1, 9), [1, 2, 5, 6, 8, 9]);
+        assert_eq!(set.gaps(3, 4), Vec::<BlockNumber>::new());
+        assert_eq!(set.gaps(9, 1), Vec::<BlockNumber>::new());
+        assert_eq!(IndexedSet::new().gaps(5, 6), [5, 6]);
+
+        let top: IndexedSet = [u64::MAX].into_iter().collect();
+        assert_eq!(top.gaps(u64::MAX - 1, u64::MAX), [u64::MAX - 1]);
+    }
+
+    #[test]
+    fn gaps_match_normalize_batch() {
+        let numbers = [100, 101, 104, 105, 105, 109];
+        let headers = numbers
+            .iter()
+            .enumerate()
+            .map(|(i, &number)| Header {
+                number,
+                hash: H256::from_low_u64_be(i as u64),
+                ..Default::default()
+            })
+            .collect();
+        let (_, missing) = normalize_batch(headers);
+
+        let set: IndexedSet = numbers.into_iter().collect();
+        assert_eq!(set.gaps(100, 109), missing);
+    }
+}
@@ -1,3 +1,41 @@
+mod batch;
+mod block_id;
+mod chain;
+mod constants;
+mod convert;
+mod diff;
+mod event;
+mod fee;
+mod header;
+mod indexed;
+mod parse;
+mod pending;
+mod phase;
+mod reward;
+mod stall;
+mod stats;
+mod validation;
+mod withdrawals;
+
+pub use batch::{convert_batch, normalize_batch};
+pub use block_id::BlockId;
+pub use chain::Chain;
+pub use constants::{EMPTY_TRIE_ROOT, EMPTY_UNCLE_HASH};
+pub use convert::{requests_hash, InvalidBlockError};
+pub use diff::{diff, FieldDiff};
+pub use event::{DecodedLog, EventFilter, EventFilterError};
+pub use fee::next_base_fee;
+pub use header::{BlockHash, BlockNumber, Header};
+pub use indexed::IndexedSet;
+pub use parse::{parse_block_number, BlockNumberParseError};
+pub use pending::PendingHeader;
+pub use phase::IndexerPhase;
+pub use reward::RewardInputs;
+pub use stall::{Liveness, StallDetector};
+pub use stats::BlockStats;
+pub use validation::{block_time, validate_link, LinkError, PowFieldsError};
+pub use withdrawals::sum_withdrawals;
+
 pub fn add(left: usize, right: usize) -> usize {
     left + right
 }
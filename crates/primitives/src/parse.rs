@@ -0,0 +1,133 @@
+use crate::BlockNumber;
+
+/// Why a configured block number could not be parsed. Every variant names the variable.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum BlockNumberParseError {
+    #[error("{var} is empty, expected a block number")]
+    Empty { var: String },
+    #[error("{var}={value} is negative, block numbers start at 0")]
+    Negative { var: String, value: String },
+    #[error("{var}={value} does not fit in a 64-bit block number")]
+    Overflow { var: String, value: String },
+    #[error("{var}={value} is not a decimal or 0x-prefixed hex block number")]
+    Invalid { var: String, value: String },
+}
+
+/// Parses a block number from configuration, e.g. `START_BLOCK` or `--from`.
+///
+/// Accepts decimal digits or `0x`-prefixed hex, with surrounding whitespace ignored. `var` is
+/// the name of the variable or flag being parsed and only appears in the error.
+pub fn parse_block_number(var: &str, s: &str) -> Result<BlockNumber, BlockNumberParseError> {
+    let value = s.trim();
+    if value.is_empty() {
+        return Err(BlockNumberParseError::Empty {
+            var: var.to_owned(),
+        });
+    }
+    if value.starts_with('-') {
+        return Err(BlockNumberParseError::Negative {
+            var: var.to_owned(),
+            value: value.to_owned(),
+        });
+    }
+
+    let (digits, radix) = match value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+    {
+        Some(hex) => (hex, 16),
+        None => (value, 10),
+    };
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return Err(BlockNumberParseError::Invalid {
+            var: var.to_owned(),
+            value: value.to_owned(),
+        });
+    }
+
+    // Only overflow is left once the digits have been validated.
+    BlockNumber::from_str_radix(digits, radix).map_err(|_| BlockNumberParseError::Overflow {
+        var: var.to_owned(),
+        value: value.to_owned(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_decimal() {
+        assert_eq!(parse_block_number("START_BLOCK", "0"), Ok(0));
+        assert_eq!(
+            parse_block_number("START_BLOCK", " 17000000 "),
+            Ok(17_000_000)
+        );
+        assert_eq!(
+            parse_block_number("START_BLOCK", "18446744073709551615"),
+            Ok(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn parses_hex() {
+        assert_eq!(parse_block_number("START_BLOCK", "0x10"), Ok(16));
+        assert_eq!(parse_block_number("--from", "0XFF"), Ok(255));
+        assert_eq!(
+            parse_block_number("--to", "0xffffffffffffffff"),
+            Ok(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        let invalid = |value: &str| BlockNumberParseError::Invalid {
+            var: "START_BLOCK".to_owned(),
+            value: value.to_owned(),
+        };
+
+        assert_eq!(
+            parse_block_number("START_BLOCK", "  "),
+            Err(BlockNumberParseError::Empty {
+                var: "START_BLOCK".to_owned(),
+            })
+        );
+        assert_eq!(
+            parse_block_number("START_BLOCK", "-1"),
+            Err(BlockNumberParseError::Negative {
+                var: "START_BLOCK".to_owned(),
+                value: "-1".to_owned(),
+            })
+        );
+        assert_eq!(
+            parse_block_number("START_BLOCK", "18446744073709551616"),
+            Err(BlockNumberParseError::Overflow {
+                var: "START_BLOCK".to_owned(),
+                value: "18446744073709551616".to_owned(),
+            })
+        );
+        assert!(matches!(
+            parse_block_number("--to", "0x10000000000000000"),
+            Err(BlockNumberParseError::Overflow { .. })
+        ));
+        assert_eq!(parse_block_number("START_BLOCK", "0x"), Err(invalid("0x")));
+        assert_eq!(
+            parse_block_number("START_BLOCK", "12a"),
+            Err(invalid("12a"))
+        );
+        assert_eq!(parse_block_number("START_BLOCK", "+5"), Err(invalid("+5")));
+        assert_eq!(
+            parse_block_number("START_BLOCK", "1e6"),
+            Err(invalid("1e6"))
+        );
+    }
+
+    #[test]
+    fn error_names_the_variable() {
+        let error = parse_block_number("START_BLOCK", "-5").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "START_BLOCK=-5 is negative, block numbers start at 0"
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use ethers_core::types::{Block, Bytes, H160, H64, U256};
+
+use crate::{BlockHash, BlockNumber};
+
+/// The subset of a pending block's header that nodes report.
+///
+/// A pending block is still being built, so nodes leave out `hash` and `nonce`, and some also
+/// leave out `number` or `miner`. It is kept apart from [`crate::Header`] so it can never be
+/// mistaken for a canonical block.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PendingHeader {
+    pub hash: Option<BlockHash>,
+    pub parent_hash: BlockHash,
+    pub author: Option<H160>,
+    pub number: Option<BlockNumber>,
+    pub gas_used: u64,
+    pub gas_limit: u64,
+    pub extra_data: Bytes,
+    pub timestamp: u64,
+    pub nonce: Option<H64>,
+    pub base_fee_per_gas: Option<u64>,
+    pub transaction_count: usize,
+}
+
+/// Pending blocks are never stored, so out-of-range quantities saturate instead of failing.
+fn saturating_u64(value: U256) -> u64 {
+    u64::try_from(value).unwrap_or(u64::MAX)
+}
+
+impl<TX> From<&Block<TX>> for PendingHeader {
+    fn from(block: &Block<TX>) -> Self {
+        PendingHeader {
+            hash: block.hash,
+            parent_hash: block.parent_hash,
+            author: block.author,
+            number: block.number.map(|number| number.as_u64()),
+            gas_used: saturating_u64(block.gas_used),
+            gas_limit: saturating_u64(block.gas_limit),
+            extra_data: block.extra_data.clone(),
+            timestamp: saturating_u64(block.timestamp),
+            nonce: block.nonce,
+            base_fee_per_gas: block.base_fee_per_gas.map(saturating_u64),
+            transaction_count: block.transactions.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers_core::types::H256;
+
+    use super::*;
+
+    /// A pending block as returned by `eth_getBlockByNumber("pending", false)`.
+    const PENDING_BLOCK: &str = r#"{
+        "hash": null,
+        "parentHash": "0x54732505f10ea67ae1ececf3dd4aa0bef1d78770f234bd26ad2cd1eec0b36596",
+        "sha3Uncles": "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347",
+        "miner": null,
+        "stateRoot": "0x0000000000000000000000000000000000000000000000000000000000000000",
+        "transactionsRoot": "0x0000000000000000000000000000000000000000000000000000000000000000",
+        "receiptsRoot": "0x0000000000000000000000000000000000000000000000000000000000000000",
+        "logsBloom": null,
+        "difficulty": "0x0",
+        "number": "0x10c53b5",
+        "gasLimit": "0x1c9c380",
+        "gasUsed": "0x5f5e10",
+        "timestamp": "0x649d7e93",
+        "extraData": "0x",
+        "mixHash": null,
+        "nonce": null,
+        "baseFeePerGas": "0x5dc6a0b53",
+        "uncles": [],
+        "transactions": [
+            "0x0101010101010101010101010101010101010101010101010101010101010101",
+            "0x0202020202020202020202020202020202020202020202020202020202020202"
+        ]
+    }"#;
+
+    #[test]
+    fn converts_pending_block_without_hash_or_nonce() {
+        let block: Block<H256> = serde_json::from_str(PENDING_BLOCK).unwrap();
+
+        let pending = PendingHeader::from(&block);
+
+        assert_eq!(pending.hash, None);
+        assert_eq!(pending.nonce, None);
+        assert_eq!(pending.author, None);
+        assert_eq!(pending.parent_hash, block.parent_hash);
+        assert_eq!(pending.number, Some(17_585_077));
+        assert_eq!(pending.gas_limit, 30_000_000);
+        assert_eq!(pending.gas_used, 6_250_000);
+        assert_eq!(pending.timestamp, 1_688_043_155);
+        assert_eq!(pending.base_fee_per_gas, Some(25_172_773_715));
+        assert_eq!(pending.transaction_count, 2);
+    }
+
+    #[test]
+    fn tolerates_a_missing_number() {
+        let block = Block::<H256> {
+            number: None,
+            timestamp: U256::MAX,
+            ..Default::default()
+        };
+
+        let pending = PendingHeader::from(&block);
+
+        assert_eq!(pending.number, None);
+        assert_eq!(pending.timestamp, u64::MAX);
+    }
+}
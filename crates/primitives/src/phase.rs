@@ -0,0 +1,56 @@
+use crate::BlockNumber;
+
+/// Whether the indexer is catching up to the chain tip or following it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexerPhase {
+    /// Backfilling towards `target`, the chain tip last observed.
+    Syncing { target: BlockNumber },
+    /// Within `threshold` blocks of the tip.
+    Following,
+}
+
+impl IndexerPhase {
+    /// Computes the phase after observing the indexed `head` and the chain `tip`.
+    ///
+    /// The indexer starts following once the lag drops below `threshold` and goes back to
+    /// syncing once it exceeds it. A lag of exactly `threshold` keeps the current phase, so the
+    /// phase does not flap around the boundary.
+    pub fn next(self, head: BlockNumber, tip: BlockNumber, threshold: u64) -> IndexerPhase {
+        let lag = tip.saturating_sub(head);
+        match self {
+            IndexerPhase::Syncing { .. } if lag < threshold => IndexerPhase::Following,
+            IndexerPhase::Syncing { .. } => IndexerPhase::Syncing { target: tip },
+            IndexerPhase::Following if lag > threshold => IndexerPhase::Syncing { target: tip },
+            IndexerPhase::Following => IndexerPhase::Following,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phase_follows_lag() {
+        let threshold = 10;
+        let phase = IndexerPhase::Syncing { target: 1_000 };
+
+        let phase = phase.next(500, 1_000, threshold);
+        assert_eq!(phase, IndexerPhase::Syncing { target: 1_000 });
+
+        let phase = phase.next(995, 1_002, threshold);
+        assert_eq!(phase, IndexerPhase::Following);
+
+        let phase = phase.next(1_000, 1_010, threshold);
+        assert_eq!(phase, IndexerPhase::Following);
+
+        let phase = phase.next(1_000, 1_050, threshold);
+        assert_eq!(phase, IndexerPhase::Syncing { target: 1_050 });
+
+        let phase = phase.next(1_040, 1_050, threshold);
+        assert_eq!(phase, IndexerPhase::Syncing { target: 1_050 });
+
+        let phase = phase.next(1_049, 1_051, threshold);
+        assert_eq!(phase, IndexerPhase::Following);
+    }
+}
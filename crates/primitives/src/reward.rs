@@ -0,0 +1,53 @@
+use ethers_core::types::H160;
+
+use crate::{BlockNumber, Header};
+
+/// The subset of a header needed to compute block and uncle rewards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RewardInputs {
+    pub number: BlockNumber,
+    pub author: H160,
+    pub base_fee_per_gas: Option<u64>,
+    pub gas_used: u64,
+    pub uncle_count: usize,
+}
+
+impl Header {
+    /// Projects the fields reward calculators need out of the header.
+    pub fn reward_inputs(&self) -> RewardInputs {
+        RewardInputs {
+            number: self.number,
+            author: self.author,
+            base_fee_per_gas: self.base_fee_per_gas,
+            gas_used: self.gas_used,
+            uncle_count: self.uncles.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers_core::types::H256;
+
+    use super::*;
+
+    #[test]
+    fn reward_inputs_match_header() {
+        let header = Header {
+            number: 12_965_000,
+            author: H160::repeat_byte(0x0f),
+            base_fee_per_gas: Some(1_000_000_000),
+            gas_used: 30_025_257,
+            uncles: vec![H256::repeat_byte(0x01), H256::repeat_byte(0x02)],
+            ..Default::default()
+        };
+
+        let inputs = header.reward_inputs();
+
+        assert_eq!(inputs.number, header.number);
+        assert_eq!(inputs.author, header.author);
+        assert_eq!(inputs.base_fee_per_gas, header.base_fee_per_gas);
+        assert_eq!(inputs.gas_used, header.gas_used);
+        assert_eq!(inputs.uncle_count, 2);
+    }
+}
@@ -0,0 +1,115 @@
+use std::time::{Duration, Instant};
+
+use crate::BlockNumber;
+
+/// What the indexer's recent progress says about its health.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Liveness {
+    /// Caught up with the tip, so there is nothing to index.
+    Idle,
+    /// Behind the tip and indexed a block within the timeout.
+    Progressing,
+    /// Behind the tip with no block indexed for `since`.
+    Stalled { since: Duration },
+}
+
+/// Dead-man's switch that flags an indexer that stops advancing while behind the tip.
+#[derive(Debug, Clone)]
+pub struct StallDetector {
+    timeout: Duration,
+    last_head: Option<BlockNumber>,
+    last_progress: Instant,
+}
+
+impl StallDetector {
+    /// Starts the timeout window at `now`.
+    pub fn new(timeout: Duration, now: Instant) -> Self {
+        Self {
+            timeout,
+            last_head: None,
+            last_progress: now,
+        }
+    }
+
+    /// When the indexed head last advanced, or the indexer was last caught up.
+    pub fn last_progress(&self) -> Instant {
+        self.last_progress
+    }
+
+    /// Records the indexed `head` and chain `tip` observed at `now`.
+    ///
+    /// A lag of zero is [`Liveness::Idle`] rather than a stall however long it lasts, since a
+    /// quiet chain produces nothing to index. It also restarts the window, so the first block
+    /// after a quiet spell is not reported as stalled.
+    pub fn observe(&mut self, head: BlockNumber, tip: BlockNumber, now: Instant) -> Liveness {
+        if self.last_head.is_none_or(|last_head| head > last_head) {
+            self.last_head = Some(head);
+            self.last_progress = now;
+        }
+        if tip <= head {
+            self.last_progress = now;
+            return Liveness::Idle;
+        }
+
+        let since = now.saturating_duration_since(self.last_progress);
+        if since >= self.timeout {
+            Liveness::Stalled { since }
+        } else {
+            Liveness::Progressing
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TIMEOUT: Duration = Duration::from_secs(60);
+
+    fn secs(start: Instant, secs: u64) -> Instant {
+        start + Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn idle_chain_is_not_a_stall() {
+        let start = Instant::now();
+        let mut detector = StallDetector::new(TIMEOUT, start);
+
+        assert_eq!(detector.observe(100, 100, start), Liveness::Idle);
+        assert_eq!(detector.observe(100, 100, secs(start, 600)), Liveness::Idle);
+
+        // A new tip after the quiet spell starts a fresh window.
+        assert_eq!(
+            detector.observe(100, 101, secs(start, 601)),
+            Liveness::Progressing
+        );
+    }
+
+    #[test]
+    fn head_stuck_behind_tip_is_a_stall() {
+        let start = Instant::now();
+        let mut detector = StallDetector::new(TIMEOUT, start);
+
+        assert_eq!(detector.observe(100, 110, start), Liveness::Progressing);
+        assert_eq!(
+            detector.observe(105, 110, secs(start, 30)),
+            Liveness::Progressing
+        );
+        assert_eq!(
+            detector.observe(105, 112, secs(start, 80)),
+            Liveness::Progressing
+        );
+        assert_eq!(
+            detector.observe(105, 112, secs(start, 90)),
+            Liveness::Stalled {
+                since: Duration::from_secs(60)
+            }
+        );
+        assert_eq!(detector.last_progress(), secs(start, 30));
+
+        assert_eq!(
+            detector.observe(106, 112, secs(start, 95)),
+            Liveness::Progressing
+        );
+    }
+}
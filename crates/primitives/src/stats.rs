@@ -0,0 +1,90 @@
+use crate::{block_time, BlockHash, BlockNumber, Header};
+
+/// A slim per-block row for dashboards that don't need the full header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockStats {
+    pub number: BlockNumber,
+    pub hash: BlockHash,
+    /// `None` until headers carry a transaction count.
+    pub transaction_count: Option<u64>,
+    pub gas_used: u64,
+    pub gas_limit: u64,
+    pub base_fee_per_gas: Option<u64>,
+    /// Seconds since the parent block, see [`crate::block_time`].
+    pub block_time: Option<u64>,
+}
+
+impl Header {
+    /// Projects the fields of [`BlockStats`] that the header carries.
+    ///
+    /// `block_time` is computed from `parent` when given, and otherwise taken from
+    /// [`Header::block_time`].
+    pub fn block_stats(&self, parent: Option<&Header>) -> BlockStats {
+        BlockStats {
+            number: self.number,
+            hash: self.hash,
+            transaction_count: None,
+            gas_used: self.gas_used,
+            gas_limit: self.gas_limit,
+            base_fee_per_gas: self.base_fee_per_gas,
+            block_time: parent
+                .and_then(|parent| block_time(parent, self))
+                .or(self.block_time),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers_core::types::H256;
+
+    use super::*;
+
+    #[test]
+    fn block_stats_match_header() {
+        let header = Header {
+            number: 17_000_000,
+            hash: H256::repeat_byte(0x0c),
+            gas_used: 12_345_678,
+            gas_limit: 30_000_000,
+            base_fee_per_gas: Some(27_000_000_000),
+            ..Default::default()
+        };
+
+        let stats = header.block_stats(None);
+
+        assert_eq!(stats.number, header.number);
+        assert_eq!(stats.hash, header.hash);
+        assert_eq!(stats.gas_used, header.gas_used);
+        assert_eq!(stats.gas_limit, header.gas_limit);
+        assert_eq!(stats.base_fee_per_gas, header.base_fee_per_gas);
+        assert_eq!(stats.transaction_count, None);
+        assert_eq!(stats.block_time, None);
+    }
+
+    #[test]
+    fn block_stats_with_linked_parent() {
+        let parent = Header {
+            number: 17_000_000,
+            hash: H256::repeat_byte(0x0c),
+            timestamp: 1_680_911_891,
+            ..Default::default()
+        };
+        let child = Header {
+            number: 17_000_001,
+            hash: H256::repeat_byte(0x0d),
+            parent_hash: parent.hash,
+            timestamp: 1_680_911_903,
+            ..Default::default()
+        };
+
+        assert_eq!(child.block_stats(Some(&parent)).block_time, Some(12));
+        assert_eq!(parent.block_stats(Some(&child)).block_time, None);
+
+        let stored = Header {
+            block_time: Some(12),
+            ..child.clone()
+        };
+        assert_eq!(stored.block_stats(None).block_time, Some(12));
+    }
+}
@@ -0,0 +1,377 @@
+use crate::{fee::INITIAL_BASE_FEE, next_base_fee, BlockHash, BlockNumber, Chain, Header};
+
+/// A broken invariant between a header and the parent it claims.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum LinkError {
+    #[error("parent hash mismatch: expected {expected:?}, found {found:?}")]
+    ParentHashMismatch {
+        expected: BlockHash,
+        found: BlockHash,
+    },
+    #[error("block {child} does not follow parent {parent}")]
+    NonSequentialNumber {
+        parent: BlockNumber,
+        child: BlockNumber,
+    },
+    #[error("timestamp {child} is not after parent timestamp {parent}")]
+    NonIncreasingTimestamp { parent: u64, child: u64 },
+    #[error("base fee mismatch: expected {expected}, found {found}")]
+    BaseFeeMismatch { expected: u64, found: u64 },
+    #[error("block {block} is past the London fork but has no base fee")]
+    MissingBaseFee { block: BlockNumber },
+    #[error("cannot compute the base fee following parent {parent}")]
+    BaseFeeUncomputable { parent: BlockNumber },
+}
+
+/// A proof-of-work field that is absent or zeroed on a block with non-zero difficulty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum PowFieldsError {
+    #[error("PoW block {0} has no nonce")]
+    MissingNonce(BlockNumber),
+    #[error("PoW block {0} has no mix hash")]
+    MissingMixHash(BlockNumber),
+}
+
+impl Header {
+    /// Checks that a pre-merge block carries a real `nonce` and `mix_hash`.
+    ///
+    /// Some nodes return these zeroed. PoS blocks are always accepted, since there `nonce` is
+    /// zero by definition and `mix_hash` holds prev-RANDAO.
+    pub fn verify_pow_fields(&self) -> Result<(), PowFieldsError> {
+        if self.is_pos() {
+            return Ok(());
+        }
+        if self.nonce.is_none_or(|nonce| nonce.is_zero()) {
+            return Err(PowFieldsError::MissingNonce(self.number));
+        }
+        if self.mix_hash.is_none_or(|mix_hash| mix_hash.is_zero()) {
+            return Err(PowFieldsError::MissingMixHash(self.number));
+        }
+        Ok(())
+    }
+}
+
+/// Checks that `child` correctly extends `parent` on `chain`.
+///
+/// From the chain's London block onwards every block must carry a base fee: the fork block
+/// itself starts at the EIP-1559 initial base fee, and later blocks must match
+/// [`next_base_fee`] of their parent. That formula uses the Ethereum L1 parameters, so base fees
+/// are only checked on chains with a known [`Chain::london_block`].
+pub fn validate_link(chain: Chain, parent: &Header, child: &Header) -> Result<(), LinkError> {
+    check_parent(parent, child)?;
+
+    let Some(london_block) = chain.london_block() else {
+        return Ok(());
+    };
+    if child.number < london_block {
+        return Ok(());
+    }
+    let found = child.base_fee_per_gas.ok_or(LinkError::MissingBaseFee {
+        block: child.number,
+    })?;
+    let expected = if child.number == london_block {
+        INITIAL_BASE_FEE
+    } else {
+        if parent.base_fee_per_gas.is_none() {
+            return Err(LinkError::MissingBaseFee {
+                block: parent.number,
+            });
+        }
+        next_base_fee(parent).ok_or(LinkError::BaseFeeUncomputable {
+            parent: parent.number,
+        })?
+    };
+    if found != expected {
+        return Err(LinkError::BaseFeeMismatch { expected, found });
+    }
+    Ok(())
+}
+
+/// Returns the seconds between `parent` and `child`, or `None` unless `child` directly extends
+/// `parent`.
+pub fn block_time(parent: &Header, child: &Header) -> Option<u64> {
+    check_parent(parent, child).ok()?;
+    Some(child.timestamp - parent.timestamp)
+}
+
+/// Checks the chain-independent part of a link: parent hash, number and timestamp.
+pub(crate) fn check_parent(parent: &Header, child: &Header) -> Result<(), LinkError> {
+    if child.parent_hash != parent.hash {
+        return Err(LinkError::ParentHashMismatch {
+            expected: parent.hash,
+            found: child.parent_hash,
+        });
+    }
+    if parent.number.checked_add(1) != Some(child.number) {
+        return Err(LinkError::NonSequentialNumber {
+            parent: parent.number,
+            child: child.number,
+        });
+    }
+    if child.timestamp <= parent.timestamp {
+        return Err(LinkError::NonIncreasingTimestamp {
+            parent: parent.timestamp,
+            child: child.timestamp,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers_core::types::{H256, H64, U256};
+
+    use super::*;
+    use crate::diff;
+
+    fn linked_pair() -> (Header, Header) {
+        let parent = Header {
+            hash: H256::repeat_byte(0x01),
+            number: 12_965_000,
+            timestamp: 1_628_166_822,
+            gas_used: 30_025_257,
+            gas_limit: 30_029_122,
+            base_fee_per_gas: Some(1_000_000_000),
+            ..Default::default()
+        };
+        let child = Header {
+            hash: H256::repeat_byte(0x02),
+            parent_hash: parent.hash,
+            number: 12_965_001,
+            timestamp: 1_628_166_835,
+            gas_limit: 30_000_000,
+            base_fee_per_gas: Some(1_124_967_822),
+            ..Default::default()
+        };
+        (parent, child)
+    }
+
+    #[test]
+    fn valid_link_passes() {
+        let (parent, child) = linked_pair();
+        assert_eq!(validate_link(Chain::Mainnet, &parent, &child), Ok(()));
+    }
+
+    #[test]
+    fn parent_hash_mismatch() {
+        let (parent, mut child) = linked_pair();
+        child.parent_hash = H256::repeat_byte(0xff);
+
+        assert_eq!(
+            validate_link(Chain::Mainnet, &parent, &child),
+            Err(LinkError::ParentHashMismatch {
+                expected: parent.hash,
+                found: child.parent_hash,
+            })
+        );
+    }
+
+    #[test]
+    fn non_sequential_number() {
+        let (parent, mut child) = linked_pair();
+        child.number = parent.number + 2;
+
+        assert_eq!(
+            validate_link(Chain::Mainnet, &parent, &child),
+            Err(LinkError::NonSequentialNumber {
+                parent: parent.number,
+                child: child.number,
+            })
+        );
+    }
+
+    #[test]
+    fn non_increasing_timestamp() {
+        let (parent, mut child) = linked_pair();
+        child.timestamp = parent.timestamp;
+
+        assert_eq!(
+            validate_link(Chain::Mainnet, &parent, &child),
+            Err(LinkError::NonIncreasingTimestamp {
+                parent: parent.timestamp,
+                child: child.timestamp,
+            })
+        );
+    }
+
+    #[test]
+    fn base_fee_mismatch() {
+        let (parent, mut child) = linked_pair();
+        child.base_fee_per_gas = Some(1_000_000_000);
+
+        assert_eq!(
+            validate_link(Chain::Mainnet, &parent, &child),
+            Err(LinkError::BaseFeeMismatch {
+                expected: 1_124_967_822,
+                found: 1_000_000_000,
+            })
+        );
+    }
+
+    #[test]
+    fn missing_base_fee_after_london() {
+        let (mut parent, mut child) = linked_pair();
+        child.base_fee_per_gas = None;
+        assert_eq!(
+            validate_link(Chain::Mainnet, &parent, &child),
+            Err(LinkError::MissingBaseFee {
+                block: child.number,
+            })
+        );
+
+        child.base_fee_per_gas = Some(1_124_967_822);
+        parent.base_fee_per_gas = None;
+        assert_eq!(
+            validate_link(Chain::Mainnet, &parent, &child),
+            Err(LinkError::MissingBaseFee {
+                block: parent.number,
+            })
+        );
+    }
+
+    #[test]
+    fn london_fork_block_starts_at_initial_base_fee() {
+        let (mut fork, _) = linked_pair();
+        let parent = Header {
+            hash: H256::repeat_byte(0x00),
+            number: fork.number - 1,
+            timestamp: fork.timestamp - 13,
+            base_fee_per_gas: None,
+            ..Default::default()
+        };
+        fork.parent_hash = parent.hash;
+        assert_eq!(validate_link(Chain::Mainnet, &parent, &fork), Ok(()));
+
+        fork.base_fee_per_gas = Some(7);
+        assert_eq!(
+            validate_link(Chain::Mainnet, &parent, &fork),
+            Err(LinkError::BaseFeeMismatch {
+                expected: 1_000_000_000,
+                found: 7,
+            })
+        );
+
+        fork.base_fee_per_gas = None;
+        assert_eq!(
+            validate_link(Chain::Mainnet, &parent, &fork),
+            Err(LinkError::MissingBaseFee { block: fork.number })
+        );
+    }
+
+    #[test]
+    fn pre_london_blocks_have_no_base_fee_check() {
+        let grandparent = Header {
+            hash: H256::repeat_byte(0x03),
+            number: 12_964_998,
+            timestamp: 1_628_166_800,
+            ..Default::default()
+        };
+        let parent = Header {
+            hash: H256::repeat_byte(0x04),
+            parent_hash: grandparent.hash,
+            number: 12_964_999,
+            timestamp: 1_628_166_810,
+            ..Default::default()
+        };
+
+        assert_eq!(validate_link(Chain::Mainnet, &grandparent, &parent), Ok(()));
+    }
+
+    #[test]
+    fn uncomputable_base_fee() {
+        let (mut parent, child) = linked_pair();
+        parent.gas_limit = 1;
+
+        assert_eq!(
+            validate_link(Chain::Mainnet, &parent, &child),
+            Err(LinkError::BaseFeeUncomputable {
+                parent: parent.number,
+            })
+        );
+    }
+
+    #[test]
+    fn custom_chain_skips_base_fee_check() {
+        let (parent, mut child) = linked_pair();
+        child.base_fee_per_gas = Some(1);
+
+        assert_eq!(validate_link(Chain::Custom(10), &parent, &child), Ok(()));
+
+        assert!(matches!(
+            validate_link(Chain::Custom(1), &parent, &child),
+            Err(LinkError::BaseFeeMismatch { .. })
+        ));
+
+        child.parent_hash = H256::repeat_byte(0xff);
+        assert!(matches!(
+            validate_link(Chain::Custom(10), &parent, &child),
+            Err(LinkError::ParentHashMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn block_time_of_linked_pair() {
+        let (parent, mut child) = linked_pair();
+        assert_eq!(block_time(&parent, &child), Some(13));
+
+        let unfilled = child.clone();
+        child.block_time = block_time(&parent, &child);
+        let fields: Vec<_> = diff(&unfilled, &child).iter().map(|d| d.field).collect();
+        assert_eq!(fields, ["block_time"]);
+
+        let unrelated = Header {
+            parent_hash: H256::repeat_byte(0xff),
+            ..child.clone()
+        };
+        assert_eq!(block_time(&parent, &unrelated), None);
+        assert_eq!(block_time(&child, &parent), None);
+    }
+
+    fn pow_header() -> Header {
+        Header {
+            number: 1_000_000,
+            difficulty: U256::from(12_549_332_509_227u64),
+            nonce: Some(H64::from_low_u64_be(0xcf7e_0ac9_1061_1e25)),
+            mix_hash: Some(H256::repeat_byte(0x4c)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn pow_fields_present() {
+        assert_eq!(pow_header().verify_pow_fields(), Ok(()));
+    }
+
+    #[test]
+    fn pow_fields_missing() {
+        let header = Header {
+            nonce: Some(H64::zero()),
+            ..pow_header()
+        };
+        assert_eq!(
+            header.verify_pow_fields(),
+            Err(PowFieldsError::MissingNonce(1_000_000))
+        );
+
+        let header = Header {
+            mix_hash: None,
+            ..pow_header()
+        };
+        assert_eq!(
+            header.verify_pow_fields(),
+            Err(PowFieldsError::MissingMixHash(1_000_000))
+        );
+    }
+
+    #[test]
+    fn pos_skips_pow_fields() {
+        let header = Header {
+            number: 17_000_000,
+            difficulty: U256::zero(),
+            nonce: Some(H64::zero()),
+            mix_hash: Some(H256::repeat_byte(0x7a)),
+            ..Default::default()
+        };
+        assert_eq!(header.verify_pow_fields(), Ok(()));
+    }
+}
@@ -0,0 +1,43 @@
+use ethers_core::types::{Withdrawal, U256};
+
+/// Sums the amounts of a block's withdrawals, in gwei.
+pub fn sum_withdrawals(withdrawals: &[Withdrawal]) -> U256 {
+    withdrawals.iter().fold(U256::zero(), |total, withdrawal| {
+        total.saturating_add(withdrawal.amount)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sum_withdrawals_of_mainnet_block() {
+        // The withdrawals of mainnet block 17585076.
+        let withdrawals: Vec<Withdrawal> = serde_json::from_str(
+            r#"[
+                {"index":"0x86510f","validatorIndex":"0x92686","address":"0xb9d7934878b5fb9610b3fe8a5e441e8fad7e293f","amount":"0xd6be17"},
+                {"index":"0x865110","validatorIndex":"0x92687","address":"0xb9d7934878b5fb9610b3fe8a5e441e8fad7e293f","amount":"0xd624ea"},
+                {"index":"0x865111","validatorIndex":"0x92688","address":"0xb9d7934878b5fb9610b3fe8a5e441e8fad7e293f","amount":"0xd5e738"},
+                {"index":"0x865112","validatorIndex":"0x92689","address":"0xb9d7934878b5fb9610b3fe8a5e441e8fad7e293f","amount":"0xd6072a"},
+                {"index":"0x865113","validatorIndex":"0x9268a","address":"0xb9d7934878b5fb9610b3fe8a5e441e8fad7e293f","amount":"0xd60439"},
+                {"index":"0x865114","validatorIndex":"0x9268b","address":"0x602f2e120a9956f2ad1ce47ced286fcefbba9f8c","amount":"0xd4c6d8"},
+                {"index":"0x865115","validatorIndex":"0x9268c","address":"0x602f2e120a9956f2ad1ce47ced286fcefbba9f8c","amount":"0xd51370"},
+                {"index":"0x865116","validatorIndex":"0x9268d","address":"0x602f2e120a9956f2ad1ce47ced286fcefbba9f8c","amount":"0xd59c2e"},
+                {"index":"0x865117","validatorIndex":"0x9268e","address":"0x602f2e120a9956f2ad1ce47ced286fcefbba9f8c","amount":"0xd5870c"},
+                {"index":"0x865118","validatorIndex":"0x9268f","address":"0x602f2e120a9956f2ad1ce47ced286fcefbba9f8c","amount":"0xd4ca26"},
+                {"index":"0x865119","validatorIndex":"0x92690","address":"0x602f2e120a9956f2ad1ce47ced286fcefbba9f8c","amount":"0xd57383"},
+                {"index":"0x86511a","validatorIndex":"0x92691","address":"0x3893b81bd6de06ca16bb23c104c3fd88d992e965","amount":"0xd4effb"},
+                {"index":"0x86511b","validatorIndex":"0x92692","address":"0x3893b81bd6de06ca16bb23c104c3fd88d992e965","amount":"0xd5437e"},
+                {"index":"0x86511c","validatorIndex":"0x92693","address":"0x3893b81bd6de06ca16bb23c104c3fd88d992e965","amount":"0x2f05289"},
+                {"index":"0x86511d","validatorIndex":"0x92694","address":"0x3893b81bd6de06ca16bb23c104c3fd88d992e965","amount":"0xd5d7fd"},
+                {"index":"0x86511e","validatorIndex":"0x92695","address":"0xa921adb840ed4633e5d9128fe396b63d4ec1a454","amount":"0xd66d96"}
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(withdrawals.len(), 16);
+        assert_eq!(sum_withdrawals(&withdrawals), U256::from(259_316_828u64));
+        assert_eq!(sum_withdrawals(&[]), U256::zero());
+    }
+}